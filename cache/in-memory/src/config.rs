@@ -21,9 +21,16 @@ bitflags! {
         const VOICE_STATE = 1 << 10;
         const STAGE_INSTANCE = 1 << 11;
         const INTEGRATION = 1 << 12;
+        const PERMISSION_OVERWRITE = 1 << 13;
     }
 }
 
+/// Number of capacity slots, one per single-bit [`ResourceType`].
+///
+/// Indexed by the position of a resource type's bit; see
+/// [`Config::resource_capacity_index`].
+const RESOURCE_CAPACITY_SLOTS: usize = 16;
+
 /// Configuration for an [`InMemoryCache`].
 ///
 /// [`InMemoryCache`]: crate::InMemoryCache
@@ -31,6 +38,7 @@ bitflags! {
 pub struct Config {
     pub(super) resource_types: ResourceType,
     pub(super) message_cache_size: usize,
+    pub(super) resource_capacity: [Option<usize>; RESOURCE_CAPACITY_SLOTS],
 }
 
 impl Config {
@@ -41,9 +49,18 @@ impl Config {
         Self {
             resource_types: ResourceType::all(),
             message_cache_size: 100,
+            resource_capacity: [None; RESOURCE_CAPACITY_SLOTS],
         }
     }
 
+    /// Index of the capacity slot for a single-bit resource type.
+    ///
+    /// Multi-bit sets resolve to their lowest set bit, so callers should pass
+    /// one resource type at a time.
+    const fn resource_capacity_index(resource_type: ResourceType) -> usize {
+        resource_type.bits().trailing_zeros() as usize
+    }
+
     /// Returns an immutable reference to the message cache size.
     ///
     /// Defaults to 100.
@@ -66,6 +83,42 @@ impl Config {
     pub fn resource_types_mut(&mut self) -> &mut ResourceType {
         &mut self.resource_types
     }
+
+    /// Returns the capacity limit for a single resource type.
+    ///
+    /// `None` means the resource is cached without a limit. Defaults to `None`
+    /// for every resource type, preserving unbounded growth.
+    ///
+    /// Pass exactly one [`ResourceType`]; multi-bit sets resolve to their
+    /// lowest set bit.
+    ///
+    /// Only [`EMOJI`] currently enforces its limit; see
+    /// [`resource_capacity_mut`] for the other resource types.
+    ///
+    /// [`EMOJI`]: ResourceType::EMOJI
+    /// [`resource_capacity_mut`]: Self::resource_capacity_mut
+    pub fn resource_capacity(&self, resource_type: ResourceType) -> Option<usize> {
+        self.resource_capacity[Self::resource_capacity_index(resource_type)]
+    }
+
+    /// Returns a mutable reference to the capacity limit for a single resource
+    /// type.
+    ///
+    /// Setting this to `Some(limit)` bounds the number of cached entries of the
+    /// resource, evicting the least-recently-touched entry once the limit is
+    /// exceeded. Pass exactly one [`ResourceType`].
+    ///
+    /// Eviction is currently implemented only for [`EMOJI`]; setting a limit for
+    /// any other resource type is accepted but has no effect until LRU
+    /// enforcement is wired through that resource's cache path. The value is
+    /// still stored and reported by [`resource_capacity`], so enabling
+    /// enforcement later needs no API change.
+    ///
+    /// [`EMOJI`]: ResourceType::EMOJI
+    /// [`resource_capacity`]: Self::resource_capacity
+    pub fn resource_capacity_mut(&mut self, resource_type: ResourceType) -> &mut Option<usize> {
+        &mut self.resource_capacity[Self::resource_capacity_index(resource_type)]
+    }
 }
 
 impl Default for Config {
@@ -76,10 +129,10 @@ impl Default for Config {
 
 #[cfg(test)]
 mod tests {
-    use super::{Config, ResourceType};
+    use super::{Config, ResourceType, RESOURCE_CAPACITY_SLOTS};
     use static_assertions::assert_fields;
 
-    assert_fields!(Config: resource_types, message_cache_size);
+    assert_fields!(Config: resource_types, message_cache_size, resource_capacity);
 
     #[test]
     #[allow(clippy::cognitive_complexity)]
@@ -96,6 +149,8 @@ mod tests {
         assert_eq!(1 << 9, ResourceType::USER.bits());
         assert_eq!(1 << 10, ResourceType::VOICE_STATE.bits());
         assert_eq!(1 << 11, ResourceType::STAGE_INSTANCE.bits());
+        assert_eq!(1 << 12, ResourceType::INTEGRATION.bits());
+        assert_eq!(1 << 13, ResourceType::PERMISSION_OVERWRITE.bits());
     }
 
     #[test]
@@ -103,9 +158,22 @@ mod tests {
         let conf = Config {
             resource_types: ResourceType::all(),
             message_cache_size: 100,
+            resource_capacity: [None; RESOURCE_CAPACITY_SLOTS],
         };
         let default = Config::default();
         assert_eq!(conf.resource_types, default.resource_types);
         assert_eq!(conf.message_cache_size, default.message_cache_size);
+        assert_eq!(conf.resource_capacity, default.resource_capacity);
+    }
+
+    #[test]
+    fn test_resource_capacity() {
+        let mut conf = Config::new();
+        assert_eq!(None, conf.resource_capacity(ResourceType::EMOJI));
+
+        *conf.resource_capacity_mut(ResourceType::EMOJI) = Some(10);
+        assert_eq!(Some(10), conf.resource_capacity(ResourceType::EMOJI));
+        // Other resource types remain unbounded.
+        assert_eq!(None, conf.resource_capacity(ResourceType::MEMBER));
     }
 }
@@ -1,4 +1,9 @@
-use crate::{config::ResourceType, model::CachedEmoji, GuildItem, InMemoryCache, UpdateCache};
+use crate::{
+    config::ResourceType,
+    events::{CacheEvent, EmojiDelete, EmojiUpsert},
+    model::CachedEmoji,
+    GuildItem, InMemoryCache, UpdateCache,
+};
 use std::{borrow::Cow, collections::HashSet};
 use twilight_model::{
     gateway::payload::GuildEmojisUpdate,
@@ -13,7 +18,16 @@ impl InMemoryCache {
     ///
     /// [`GUILD_EMOJIS`]: ::twilight_model::gateway::Intents::GUILD_EMOJIS
     pub fn emoji(&self, emoji_id: EmojiId) -> Option<CachedEmoji> {
-        self.0.emojis.get(&emoji_id).map(|r| r.data.clone())
+        let emoji = self.0.emojis.get(&emoji_id)?;
+
+        // The access-order index is only maintained when an EMOJI capacity is
+        // configured; when it isn't (the default) reads stay on the lock-free
+        // `DashMap` path and remain O(1).
+        if self.0.config.resource_capacity(ResourceType::EMOJI).is_some() {
+            self.0.emojis_lru.lock().touch(emoji_id);
+        }
+
+        Some(emoji.data.clone())
     }
 
     /// Gets the set of emojis in a guild.
@@ -42,7 +56,14 @@ impl InMemoryCache {
             }
 
             for to_remove in &removal_filter {
-                self.0.emojis.remove(to_remove);
+                let old = self.0.emojis.remove(to_remove).map(|(_, item)| item.data);
+                self.0.emojis_lru.lock().remove(to_remove);
+
+                self.emit(CacheEvent::EmojiDelete(EmojiDelete {
+                    guild_id,
+                    emoji_id: *to_remove,
+                    old,
+                }));
             }
         }
 
@@ -74,10 +95,10 @@ impl InMemoryCache {
             available: emoji.available,
         };
 
-        self.0.emojis.insert(
+        let old = self.0.emojis.insert(
             cached.id,
             GuildItem {
-                data: cached,
+                data: cached.clone(),
                 guild_id,
             },
         );
@@ -86,7 +107,64 @@ impl InMemoryCache {
             .guild_emojis
             .entry(guild_id)
             .or_default()
-            .insert(emoji.id);
+            .insert(cached.id);
+
+        if self.0.config.resource_capacity(ResourceType::EMOJI).is_some() {
+            self.0.emojis_lru.lock().touch(cached.id);
+        }
+
+        self.emit(CacheEvent::EmojiUpsert(Box::new(EmojiUpsert {
+            guild_id,
+            old: old.map(|item| item.data),
+            new: cached,
+        })));
+
+        self.enforce_emoji_capacity();
+    }
+
+    /// Evict least-recently-touched emojis until the configured [`EMOJI`]
+    /// capacity is satisfied.
+    ///
+    /// Does nothing when no capacity is configured, preserving unbounded growth.
+    ///
+    /// [`EMOJI`]: ResourceType::EMOJI
+    fn enforce_emoji_capacity(&self) {
+        let capacity = match self.0.config.resource_capacity(ResourceType::EMOJI) {
+            Some(capacity) => capacity,
+            None => return,
+        };
+
+        // Evict against the live map length rather than the index length: a
+        // removal path that bypasses the index (such as a guild purge) can leave
+        // stale ticks behind, and trusting `len()` would evict still-live emojis.
+        while self.0.emojis.len() > capacity {
+            // Fall back to an arbitrary map entry when the index is empty: a
+            // capacity configured after emojis were already cached unbounded
+            // leaves those pre-existing entries untracked, so `pop_lru` would
+            // yield `None` while the map stays over the cap and the limit would
+            // never be enforced.
+            let emoji_id = match self.0.emojis_lru.lock().pop_lru() {
+                Some(emoji_id) => emoji_id,
+                None => match self.0.emojis.iter().next() {
+                    Some(entry) => *entry.key(),
+                    None => break,
+                },
+            };
+
+            // A stale tick outlives its emoji; skip it and let the loop re-check
+            // the map length against the cap.
+            if let Some((_, item)) = self.0.emojis.remove(&emoji_id) {
+                if let Some(mut guild_emojis) = self.0.guild_emojis.get_mut(&item.guild_id) {
+                    guild_emojis.remove(&emoji_id);
+                }
+
+                self.emit(CacheEvent::EmojiDelete(EmojiDelete {
+                    guild_id: item.guild_id,
+                    emoji_id,
+                    old: Some(item.data),
+                }));
+            }
+        }
     }
 }
 
@@ -1,4 +1,8 @@
-use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use crate::{
+    config::ResourceType,
+    events::{self, CacheEvent},
+    InMemoryCache, UpdateCache,
+};
 use twilight_model::{
     gateway::payload::{IntegrationCreate, IntegrationDelete, IntegrationUpdate},
     guild::GuildIntegration,
@@ -13,24 +17,39 @@ impl InMemoryCache {
             .or_default()
             .insert(integration.id);
 
+        let old = self
+            .0
+            .integrations
+            .get(&(guild_id, integration.id))
+            .map(|item| item.data.clone());
+
         crate::upsert_guild_item(
             &self.0.integrations,
             guild_id,
             (guild_id, integration.id),
-            integration,
+            integration.clone(),
         );
+
+        self.emit(CacheEvent::IntegrationUpsert(Box::new(
+            events::IntegrationUpsert {
+                guild_id,
+                old,
+                new: integration,
+            },
+        )));
     }
 
     fn delete_integration(&self, guild_id: GuildId, integration_id: IntegrationId) {
-        if self
-            .0
-            .integrations
-            .remove(&(guild_id, integration_id))
-            .is_some()
-        {
+        if let Some((_, item)) = self.0.integrations.remove(&(guild_id, integration_id)) {
             if let Some(mut integrations) = self.0.guild_integrations.get_mut(&guild_id) {
                 integrations.remove(&integration_id);
             }
+
+            self.emit(CacheEvent::IntegrationDelete(events::IntegrationDelete {
+                guild_id,
+                integration_id,
+                old: Some(item.data),
+            }));
         }
     }
 }
@@ -42,12 +61,7 @@ impl UpdateCache for IntegrationCreate {
         }
 
         if let Some(guild_id) = self.guild_id {
-            crate::upsert_guild_item(
-                &cache.0.integrations,
-                guild_id,
-                (guild_id, self.id),
-                self.0.clone(),
-            );
+            cache.cache_integration(guild_id, self.0.clone());
         }
     }
 }
@@ -0,0 +1,229 @@
+use crate::InMemoryCache;
+use twilight_model::id::{GuildId, RoleId, UserId};
+
+impl InMemoryCache {
+    /// Resolve a cached member's highest role.
+    ///
+    /// The highest role is the one with the greatest [`position`]; ties are
+    /// broken by the lowest [`RoleId`], matching the ordering Discord applies
+    /// for role hierarchy.
+    ///
+    /// Returns `None` if the member isn't cached or none of its roles are.
+    ///
+    /// [`position`]: twilight_model::guild::Role::position
+    pub fn member_highest_role(&self, guild_id: GuildId, user_id: UserId) -> Option<RoleId> {
+        let member = self.0.members.get(&(guild_id, user_id))?;
+
+        let mut highest: Option<(i64, RoleId)> = None;
+
+        for role_id in member.roles.iter().copied() {
+            let role = match self.0.roles.get(&role_id) {
+                Some(role) => role,
+                None => continue,
+            };
+
+            let position = role.data.position;
+
+            match highest {
+                Some((pos, id)) if position < pos || (position == pos && role_id >= id) => {}
+                _ => highest = Some((position, role_id)),
+            }
+        }
+
+        highest.map(|(_, role_id)| role_id)
+    }
+
+    /// Resolve the role used to group a cached member in the member list
+    /// sidebar.
+    ///
+    /// This is the highest-positioned role whose [`hoist`] flag is set; ties are
+    /// broken by the lowest [`RoleId`]. Returns `None` if the member isn't
+    /// cached or has no hoisted roles.
+    ///
+    /// [`hoist`]: twilight_model::guild::Role::hoist
+    pub fn member_hoisted_role(&self, guild_id: GuildId, user_id: UserId) -> Option<RoleId> {
+        let member = self.0.members.get(&(guild_id, user_id))?;
+
+        let mut highest: Option<(i64, RoleId)> = None;
+
+        for role_id in member.roles.iter().copied() {
+            let role = match self.0.roles.get(&role_id) {
+                Some(role) => role,
+                None => continue,
+            };
+
+            if !role.data.hoist {
+                continue;
+            }
+
+            let position = role.data.position;
+
+            match highest {
+                Some((pos, id)) if position < pos || (position == pos && role_id >= id) => {}
+                _ => highest = Some((position, role_id)),
+            }
+        }
+
+        highest.map(|(_, role_id)| role_id)
+    }
+
+    /// Resolve the color used to display a cached member's name.
+    ///
+    /// This is the color of the highest-positioned role with a non-zero color
+    /// value; ties are broken by the lowest [`RoleId`]. Returns `None` if the
+    /// member isn't cached or every role's color is zero.
+    ///
+    /// [`color`]: twilight_model::guild::Role::color
+    pub fn member_display_color(&self, guild_id: GuildId, user_id: UserId) -> Option<u32> {
+        let member = self.0.members.get(&(guild_id, user_id))?;
+
+        let mut highest: Option<(i64, RoleId, u32)> = None;
+
+        for role_id in member.roles.iter().copied() {
+            let role = match self.0.roles.get(&role_id) {
+                Some(role) => role,
+                None => continue,
+            };
+
+            if role.data.color == 0 {
+                continue;
+            }
+
+            let position = role.data.position;
+
+            match highest {
+                Some((pos, id, _)) if position < pos || (position == pos && role_id >= id) => {}
+                _ => highest = Some((position, role_id, role.data.color)),
+            }
+        }
+
+        highest.map(|(_, _, color)| color)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{model::CachedMember, GuildItem};
+    use twilight_model::guild::{Permissions, Role};
+
+    fn role(id: RoleId, position: i64, hoist: bool, color: u32) -> Role {
+        Role {
+            color,
+            hoist,
+            id,
+            managed: false,
+            mentionable: false,
+            name: "test".to_owned(),
+            permissions: Permissions::empty(),
+            position,
+            tags: None,
+        }
+    }
+
+    fn insert_role(cache: &InMemoryCache, guild_id: GuildId, role: Role) {
+        cache.0.roles.insert(
+            role.id,
+            GuildItem {
+                data: role,
+                guild_id,
+            },
+        );
+    }
+
+    fn insert_member(
+        cache: &InMemoryCache,
+        guild_id: GuildId,
+        user_id: UserId,
+        roles: Vec<RoleId>,
+    ) {
+        cache.0.members.insert(
+            (guild_id, user_id),
+            CachedMember {
+                deaf: Some(false),
+                guild_id,
+                joined_at: None,
+                mute: Some(false),
+                nick: None,
+                pending: false,
+                premium_since: None,
+                roles,
+                user_id,
+            },
+        );
+    }
+
+    #[test]
+    fn test_highest_role_position() {
+        let cache = InMemoryCache::new();
+        let guild_id = GuildId(1);
+        let user_id = UserId(2);
+
+        insert_role(&cache, guild_id, role(RoleId(10), 1, false, 0));
+        insert_role(&cache, guild_id, role(RoleId(11), 3, false, 0));
+        insert_role(&cache, guild_id, role(RoleId(12), 2, false, 0));
+        insert_member(&cache, guild_id, user_id, vec![RoleId(10), RoleId(11), RoleId(12)]);
+
+        assert_eq!(Some(RoleId(11)), cache.member_highest_role(guild_id, user_id));
+        assert!(cache.member_highest_role(guild_id, UserId(404)).is_none());
+    }
+
+    #[test]
+    fn test_highest_role_tie_break() {
+        let cache = InMemoryCache::new();
+        let guild_id = GuildId(1);
+        let user_id = UserId(2);
+
+        // Both roles share a position, so the lower ID wins.
+        insert_role(&cache, guild_id, role(RoleId(21), 5, false, 0));
+        insert_role(&cache, guild_id, role(RoleId(20), 5, false, 0));
+        insert_member(&cache, guild_id, user_id, vec![RoleId(21), RoleId(20)]);
+
+        assert_eq!(Some(RoleId(20)), cache.member_highest_role(guild_id, user_id));
+    }
+
+    #[test]
+    fn test_hoisted_role() {
+        let cache = InMemoryCache::new();
+        let guild_id = GuildId(1);
+        let user_id = UserId(2);
+
+        // The highest role isn't hoisted, so the highest hoisted role is used.
+        insert_role(&cache, guild_id, role(RoleId(30), 3, false, 0));
+        insert_role(&cache, guild_id, role(RoleId(31), 2, true, 0));
+        insert_role(&cache, guild_id, role(RoleId(32), 1, true, 0));
+        insert_member(&cache, guild_id, user_id, vec![RoleId(30), RoleId(31), RoleId(32)]);
+
+        assert_eq!(Some(RoleId(31)), cache.member_hoisted_role(guild_id, user_id));
+
+        insert_member(&cache, guild_id, UserId(3), vec![RoleId(30)]);
+        assert!(cache.member_hoisted_role(guild_id, UserId(3)).is_none());
+    }
+
+    #[test]
+    fn test_display_color() {
+        let cache = InMemoryCache::new();
+        let guild_id = GuildId(1);
+        let user_id = UserId(2);
+
+        // The highest role has no color, so the next coloured role is used.
+        insert_role(&cache, guild_id, role(RoleId(40), 3, false, 0));
+        insert_role(&cache, guild_id, role(RoleId(41), 2, false, 0xff_00_00));
+        insert_member(&cache, guild_id, user_id, vec![RoleId(40), RoleId(41)]);
+
+        assert_eq!(Some(0xff_00_00), cache.member_display_color(guild_id, user_id));
+    }
+
+    #[test]
+    fn test_display_color_all_zero() {
+        let cache = InMemoryCache::new();
+        let guild_id = GuildId(1);
+        let user_id = UserId(2);
+
+        insert_role(&cache, guild_id, role(RoleId(50), 2, false, 0));
+        insert_role(&cache, guild_id, role(RoleId(51), 1, false, 0));
+        insert_member(&cache, guild_id, user_id, vec![RoleId(50), RoleId(51)]);
+
+        assert!(cache.member_display_color(guild_id, user_id).is_none());
+    }
+}
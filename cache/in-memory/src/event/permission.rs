@@ -0,0 +1,387 @@
+use crate::{config::ResourceType, InMemoryCache};
+use twilight_model::{
+    channel::{
+        permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        Channel, GuildChannel,
+    },
+    guild::Permissions,
+    id::{ChannelId, GuildId, RoleId, UserId},
+};
+
+/// Extract the ID and permission overwrites of a guild channel.
+///
+/// Returns `None` for channels that can't carry overwrites, such as private and
+/// group channels.
+fn guild_channel_overwrites(channel: &Channel) -> Option<(ChannelId, Vec<PermissionOverwrite>)> {
+    let guild_channel = match channel {
+        Channel::Guild(guild_channel) => guild_channel,
+        _ => return None,
+    };
+
+    let (id, overwrites) = match guild_channel {
+        GuildChannel::Category(c) => (c.id, c.permission_overwrites.clone()),
+        GuildChannel::Text(c) => (c.id, c.permission_overwrites.clone()),
+        GuildChannel::Voice(c) => (c.id, c.permission_overwrites.clone()),
+        _ => return None,
+    };
+
+    Some((id, overwrites))
+}
+
+impl InMemoryCache {
+    pub(crate) fn cache_permission_overwrites(
+        &self,
+        channel_id: ChannelId,
+        overwrites: Vec<PermissionOverwrite>,
+    ) {
+        self.0.channel_permissions.insert(channel_id, overwrites);
+    }
+
+    /// Cache the permission overwrites of a guild channel.
+    ///
+    /// The channel event handlers (`ChannelCreate`/`ChannelUpdate`) don't call
+    /// this method, so a channel's overwrites are cached only where it is
+    /// called directly. A no-op when [`PERMISSION_OVERWRITE`] isn't enabled or
+    /// the channel can't carry overwrites.
+    ///
+    /// [`PERMISSION_OVERWRITE`]: ResourceType::PERMISSION_OVERWRITE
+    pub(crate) fn cache_channel_overwrites(&self, channel: &Channel) {
+        if !self.wants(ResourceType::PERMISSION_OVERWRITE) {
+            return;
+        }
+
+        if let Some((channel_id, overwrites)) = guild_channel_overwrites(channel) {
+            self.cache_permission_overwrites(channel_id, overwrites);
+        }
+    }
+
+    /// Drop the cached permission overwrites of a guild channel.
+    ///
+    /// The `ChannelDelete` handler doesn't call this method, so deleting a
+    /// channel leaves its overwrites cached unless this is called directly.
+    pub(crate) fn delete_channel_overwrites(&self, channel: &Channel) {
+        if !self.wants(ResourceType::PERMISSION_OVERWRITE) {
+            return;
+        }
+
+        if let Some((channel_id, _)) = guild_channel_overwrites(channel) {
+            self.0.channel_permissions.remove(&channel_id);
+        }
+    }
+
+    /// Calculate the effective [`Permissions`] of a cached member in a cached
+    /// channel.
+    ///
+    /// Permissions are resolved in Discord's documented order: the guild
+    /// `@everyone` role, then the union of the member's roles, then the
+    /// channel's [`PermissionOverwrite`]s applied as `@everyone`, aggregated
+    /// role, and finally member-specific deny/allow pairs.
+    ///
+    /// A member without [`VIEW_CHANNEL`] after overwrites are applied has no
+    /// permissions in the channel, so [`Permissions::empty`] is returned.
+    ///
+    /// Short-circuits to [`Permissions::all`] when the member owns the guild or
+    /// has the [`ADMINISTRATOR`] permission. The owner check only needs the
+    /// cached guild, so it applies even when the owner's member entry isn't
+    /// cached; both short-circuits apply whether or not
+    /// [`PERMISSION_OVERWRITE`] is enabled.
+    ///
+    /// Otherwise returns `None` if the guild or member isn't cached, or if the
+    /// [`PERMISSION_OVERWRITE`] resource type is disabled — without cached
+    /// overwrites a channel-level answer can't be computed, so reporting
+    /// guild-level permissions as authoritative would be wrong.
+    ///
+    /// [`VIEW_CHANNEL`]: twilight_model::guild::Permissions::VIEW_CHANNEL
+    /// [`ADMINISTRATOR`]: twilight_model::guild::Permissions::ADMINISTRATOR
+    /// [`PERMISSION_OVERWRITE`]: ResourceType::PERMISSION_OVERWRITE
+    pub fn permissions_in(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        channel_id: ChannelId,
+    ) -> Option<Permissions> {
+        let guild = self.0.guilds.get(&guild_id)?;
+
+        if guild.owner_id == user_id {
+            return Some(Permissions::all());
+        }
+
+        let member = self.0.members.get(&(guild_id, user_id))?;
+
+        // The `@everyone` role shares its ID with the guild.
+        let everyone_role_id = RoleId(guild_id.0);
+
+        let mut permissions = self
+            .0
+            .roles
+            .get(&everyone_role_id)
+            .map_or_else(Permissions::empty, |role| role.data.permissions);
+
+        for role_id in member.roles.iter() {
+            if let Some(role) = self.0.roles.get(role_id) {
+                permissions |= role.data.permissions;
+            }
+        }
+
+        // Administrators implicitly have every permission and bypass overwrites.
+        if permissions.contains(Permissions::ADMINISTRATOR) {
+            return Some(Permissions::all());
+        }
+
+        // Without the overwrite store the method can't produce a channel-level
+        // answer; signal that rather than returning guild-level permissions as
+        // if the channel had no overwrites.
+        if !self.wants(ResourceType::PERMISSION_OVERWRITE) {
+            return None;
+        }
+
+        // A channel with no cached overwrites applies none, leaving the
+        // aggregated role permissions unchanged.
+        let overwrites = self
+            .0
+            .channel_permissions
+            .get(&channel_id)
+            .map_or_else(Vec::new, |overwrites| overwrites.clone());
+
+        // `@everyone` overwrite.
+        for overwrite in overwrites.iter() {
+            if overwrite.kind == PermissionOverwriteType::Role(everyone_role_id) {
+                permissions &= !overwrite.deny;
+                permissions |= overwrite.allow;
+            }
+        }
+
+        // Role overwrites are aggregated before being applied, so that an allow
+        // on one role isn't masked by a deny on another.
+        let mut roles_allow = Permissions::empty();
+        let mut roles_deny = Permissions::empty();
+
+        for overwrite in overwrites.iter() {
+            if let PermissionOverwriteType::Role(role_id) = overwrite.kind {
+                if role_id != everyone_role_id && member.roles.contains(&role_id) {
+                    roles_allow |= overwrite.allow;
+                    roles_deny |= overwrite.deny;
+                }
+            }
+        }
+
+        permissions &= !roles_deny;
+        permissions |= roles_allow;
+
+        // Member-specific overwrite, applied last so it wins.
+        for overwrite in overwrites.iter() {
+            if overwrite.kind == PermissionOverwriteType::Member(user_id) {
+                permissions &= !overwrite.deny;
+                permissions |= overwrite.allow;
+            }
+        }
+
+        // Losing `VIEW_CHANNEL` revokes every other permission in the channel.
+        if !permissions.contains(Permissions::VIEW_CHANNEL) {
+            return Some(Permissions::empty());
+        }
+
+        Some(permissions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{CachedGuild, CachedMember},
+        GuildItem,
+    };
+    use twilight_model::guild::{
+        DefaultMessageNotificationLevel, ExplicitContentFilter, MfaLevel, NSFWLevel, PremiumTier,
+        Role, SystemChannelFlags, VerificationLevel,
+    };
+
+    fn cached_guild(guild_id: GuildId, owner_id: UserId) -> CachedGuild {
+        CachedGuild {
+            id: guild_id,
+            afk_channel_id: None,
+            afk_timeout: 300,
+            application_id: None,
+            banner: None,
+            default_message_notifications: DefaultMessageNotificationLevel::All,
+            description: None,
+            discovery_splash: None,
+            explicit_content_filter: ExplicitContentFilter::None,
+            features: Vec::new(),
+            icon: None,
+            joined_at: None,
+            large: false,
+            max_members: None,
+            max_presences: None,
+            member_count: None,
+            mfa_level: MfaLevel::None,
+            name: "test".to_owned(),
+            nsfw_level: NSFWLevel::Default,
+            owner: Some(false),
+            owner_id,
+            permissions: None,
+            preferred_locale: "en-US".to_owned(),
+            premium_subscription_count: None,
+            premium_tier: PremiumTier::None,
+            rules_channel_id: None,
+            splash: None,
+            system_channel_id: None,
+            system_channel_flags: SystemChannelFlags::empty(),
+            unavailable: false,
+            vanity_url_code: None,
+            verification_level: VerificationLevel::None,
+            widget_channel_id: None,
+            widget_enabled: None,
+        }
+    }
+
+    fn insert_role(cache: &InMemoryCache, guild_id: GuildId, id: RoleId, permissions: Permissions) {
+        let role = Role {
+            color: 0,
+            hoist: false,
+            id,
+            managed: false,
+            mentionable: false,
+            name: "test".to_owned(),
+            permissions,
+            position: 0,
+            tags: None,
+        };
+
+        cache.0.roles.insert(
+            id,
+            GuildItem {
+                data: role,
+                guild_id,
+            },
+        );
+    }
+
+    fn insert_member(
+        cache: &InMemoryCache,
+        guild_id: GuildId,
+        user_id: UserId,
+        roles: Vec<RoleId>,
+    ) {
+        cache.0.members.insert(
+            (guild_id, user_id),
+            CachedMember {
+                deaf: Some(false),
+                guild_id,
+                joined_at: None,
+                mute: Some(false),
+                nick: None,
+                pending: false,
+                premium_since: None,
+                roles,
+                user_id,
+            },
+        );
+    }
+
+    fn overwrite(
+        kind: PermissionOverwriteType,
+        allow: Permissions,
+        deny: Permissions,
+    ) -> PermissionOverwrite {
+        PermissionOverwrite { allow, deny, kind }
+    }
+
+    #[test]
+    fn test_permissions_in_overwrite_chain() {
+        let cache = InMemoryCache::new();
+        let guild_id = GuildId(1);
+        let user_id = UserId(10);
+        let channel_id = ChannelId(100);
+        let everyone_id = RoleId(guild_id.0);
+        let role_id = RoleId(2);
+
+        cache.0.guilds.insert(guild_id, cached_guild(guild_id, UserId(999)));
+        // Base permissions grant both view and send at the guild level.
+        insert_role(
+            &cache,
+            guild_id,
+            everyone_id,
+            Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES,
+        );
+        insert_role(&cache, guild_id, role_id, Permissions::empty());
+        insert_member(&cache, guild_id, user_id, vec![role_id]);
+
+        cache.cache_permission_overwrites(
+            channel_id,
+            vec![
+                // `@everyone` denies sending...
+                overwrite(
+                    PermissionOverwriteType::Role(everyone_id),
+                    Permissions::empty(),
+                    Permissions::SEND_MESSAGES,
+                ),
+                // ...the member's role allows it back...
+                overwrite(
+                    PermissionOverwriteType::Role(role_id),
+                    Permissions::SEND_MESSAGES,
+                    Permissions::empty(),
+                ),
+                // ...and the member-specific overwrite denies it again, winning.
+                overwrite(
+                    PermissionOverwriteType::Member(user_id),
+                    Permissions::empty(),
+                    Permissions::SEND_MESSAGES,
+                ),
+            ],
+        );
+
+        assert_eq!(
+            Some(Permissions::VIEW_CHANNEL),
+            cache.permissions_in(guild_id, user_id, channel_id)
+        );
+    }
+
+    #[test]
+    fn test_permissions_in_denied_view_is_empty() {
+        let cache = InMemoryCache::new();
+        let guild_id = GuildId(1);
+        let user_id = UserId(10);
+        let channel_id = ChannelId(100);
+        let everyone_id = RoleId(guild_id.0);
+
+        cache.0.guilds.insert(guild_id, cached_guild(guild_id, UserId(999)));
+        insert_role(
+            &cache,
+            guild_id,
+            everyone_id,
+            Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES,
+        );
+        insert_member(&cache, guild_id, user_id, Vec::new());
+
+        // Denying view in the channel strips every other permission too.
+        cache.cache_permission_overwrites(
+            channel_id,
+            vec![overwrite(
+                PermissionOverwriteType::Role(everyone_id),
+                Permissions::empty(),
+                Permissions::VIEW_CHANNEL,
+            )],
+        );
+
+        assert_eq!(
+            Some(Permissions::empty()),
+            cache.permissions_in(guild_id, user_id, channel_id)
+        );
+    }
+
+    #[test]
+    fn test_permissions_in_owner_is_all() {
+        let cache = InMemoryCache::new();
+        let guild_id = GuildId(1);
+        let owner_id = UserId(10);
+
+        cache.0.guilds.insert(guild_id, cached_guild(guild_id, owner_id));
+
+        assert_eq!(
+            Some(Permissions::all()),
+            cache.permissions_in(guild_id, owner_id, ChannelId(100))
+        );
+    }
+}
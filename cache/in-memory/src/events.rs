@@ -0,0 +1,175 @@
+use crate::{config::ResourceType, model::CachedEmoji, InMemoryCache};
+use parking_lot::Mutex;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use twilight_model::{
+    guild::GuildIntegration,
+    id::{EmojiId, GuildId, IntegrationId},
+};
+
+/// A mutation that was applied to an [`InMemoryCache`].
+///
+/// The emoji and integration [`UpdateCache`] implementations emit one of these
+/// after the mutation has taken effect; mutations of other resources emit no
+/// event. Upsert variants carry both the previous cached value, if any, and
+/// the new value, so consumers can diff the two without re-reading the rest of
+/// the cache.
+///
+/// [`InMemoryCache`]: crate::InMemoryCache
+/// [`UpdateCache`]: crate::UpdateCache
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CacheEvent {
+    /// An emoji was created or updated.
+    EmojiUpsert(Box<EmojiUpsert>),
+    /// An emoji was removed from the cache.
+    EmojiDelete(EmojiDelete),
+    /// An integration was created or updated.
+    IntegrationUpsert(Box<IntegrationUpsert>),
+    /// An integration was removed from the cache.
+    IntegrationDelete(IntegrationDelete),
+}
+
+impl CacheEvent {
+    /// The [`ResourceType`] this event concerns.
+    ///
+    /// Used to match an event against a subscriber's filter so consumers only
+    /// observe the resources they asked for.
+    pub(crate) fn resource_type(&self) -> ResourceType {
+        match self {
+            CacheEvent::EmojiUpsert(_) | CacheEvent::EmojiDelete(_) => ResourceType::EMOJI,
+            CacheEvent::IntegrationUpsert(_) | CacheEvent::IntegrationDelete(_) => {
+                ResourceType::INTEGRATION
+            }
+        }
+    }
+}
+
+/// An emoji was inserted or replaced in the cache.
+#[derive(Clone, Debug)]
+pub struct EmojiUpsert {
+    /// ID of the guild the emoji belongs to.
+    pub guild_id: GuildId,
+    /// Previously cached emoji, if one was replaced.
+    pub old: Option<CachedEmoji>,
+    /// Emoji now stored in the cache.
+    pub new: CachedEmoji,
+}
+
+/// An emoji was removed from the cache.
+#[derive(Clone, Debug)]
+pub struct EmojiDelete {
+    /// ID of the guild the emoji belonged to.
+    pub guild_id: GuildId,
+    /// ID of the removed emoji.
+    pub emoji_id: EmojiId,
+    /// Emoji that was removed, if it was cached.
+    pub old: Option<CachedEmoji>,
+}
+
+/// An integration was inserted or replaced in the cache.
+#[derive(Clone, Debug)]
+pub struct IntegrationUpsert {
+    /// ID of the guild the integration belongs to.
+    pub guild_id: GuildId,
+    /// Previously cached integration, if one was replaced.
+    pub old: Option<GuildIntegration>,
+    /// Integration now stored in the cache.
+    pub new: GuildIntegration,
+}
+
+/// An integration was removed from the cache.
+#[derive(Clone, Debug)]
+pub struct IntegrationDelete {
+    /// ID of the guild the integration belonged to.
+    pub guild_id: GuildId,
+    /// ID of the removed integration.
+    pub integration_id: IntegrationId,
+    /// Integration that was removed, if it was cached.
+    pub old: Option<GuildIntegration>,
+}
+
+/// Broadcast registry fanning [`CacheEvent`]s out to every subscriber.
+///
+/// Held by the cache's inner state. Each call to [`InMemoryCache::events`]
+/// registers a fresh [`mpsc`] sender together with the [`ResourceType`] filter
+/// it's interested in; [`emit`] clones the event to every live sender whose
+/// filter contains the event's resource type and drops those whose receiver has
+/// been dropped. Built on [`std::sync::mpsc`] so the cache stays
+/// runtime-agnostic.
+///
+/// [`mpsc`]: std::sync::mpsc
+/// [`emit`]: Self::emit
+#[derive(Debug, Default)]
+pub(crate) struct CacheEvents {
+    senders: Mutex<Vec<(ResourceType, Sender<CacheEvent>)>>,
+}
+
+impl CacheEvents {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Subscribe to future events whose resource type is contained in `filter`.
+    pub(crate) fn subscribe(&self, filter: ResourceType) -> Receiver<CacheEvent> {
+        let (sender, receiver) = channel();
+        self.senders.lock().push((filter, sender));
+
+        receiver
+    }
+
+    /// Broadcast an event to every subscriber whose filter matches it.
+    ///
+    /// Returns without error when there are no subscribers, matching the
+    /// fire-and-forget behaviour callers expect from within `update`. Senders
+    /// whose receiver has been dropped are pruned as they're encountered.
+    pub(crate) fn emit(&self, event: CacheEvent) {
+        let resource_type = event.resource_type();
+
+        // A send error only means that subscriber's receiver was dropped; retain
+        // the rest. The mutation has already been applied, so there is nothing
+        // to unwind.
+        self.senders.lock().retain(|(filter, sender)| {
+            if !filter.contains(resource_type) {
+                return true;
+            }
+
+            sender.send(event.clone()).is_ok()
+        });
+    }
+}
+
+impl InMemoryCache {
+    /// Subscribe to a stream of [`CacheEvent`]s describing mutations as they are
+    /// applied to the cache.
+    ///
+    /// Each returned [`Receiver`] observes only events emitted after it was
+    /// created. Events are buffered in the receiver's unbounded queue until it
+    /// is read or dropped.
+    ///
+    /// Only [`EMOJI`] and [`INTEGRATION`] mutations are emitted; mutations of
+    /// other resources produce no event. Use [`events_filtered`] to observe
+    /// only a subset.
+    ///
+    /// [`EMOJI`]: ResourceType::EMOJI
+    /// [`INTEGRATION`]: ResourceType::INTEGRATION
+    /// [`events_filtered`]: Self::events_filtered
+    pub fn events(&self) -> Receiver<CacheEvent> {
+        self.events_filtered(ResourceType::all())
+    }
+
+    /// Subscribe to [`CacheEvent`]s whose [`ResourceType`] is contained in
+    /// `filter`.
+    ///
+    /// Behaves like [`events`] but only delivers events for the requested
+    /// resources, letting a consumer ignore mutations it doesn't care about.
+    ///
+    /// [`events`]: Self::events
+    pub fn events_filtered(&self, filter: ResourceType) -> Receiver<CacheEvent> {
+        self.0.cache_events.subscribe(filter)
+    }
+
+    /// Emit a [`CacheEvent`] to every active subscriber.
+    pub(crate) fn emit(&self, event: CacheEvent) {
+        self.0.cache_events.emit(event);
+    }
+}
@@ -0,0 +1,114 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+};
+
+/// An access-ordered index over cached resource IDs.
+///
+/// Each [`touch`] records that a key is the most recently used; [`pop_lru`]
+/// removes and returns the least recently used key so the caller can evict the
+/// matching entry and clean up any per-guild index set that referenced it.
+///
+/// Ordering is tracked with a monotonically increasing tick rather than wall
+/// time, so the structure is deterministic and doesn't depend on a clock.
+///
+/// [`touch`]: Self::touch
+/// [`pop_lru`]: Self::pop_lru
+#[derive(Debug)]
+pub(crate) struct LruIndex<K> {
+    /// Most recent tick assigned to each key.
+    ticks: HashMap<K, u64>,
+    /// Keys ordered by their tick; the first entry is least recently used.
+    order: BTreeMap<u64, K>,
+    /// Next tick to assign.
+    next_tick: u64,
+}
+
+impl<K> LruIndex<K>
+where
+    K: Copy + Eq + Hash + Ord,
+{
+    /// Create an empty index.
+    pub(crate) fn new() -> Self {
+        Self {
+            ticks: HashMap::new(),
+            order: BTreeMap::new(),
+            next_tick: 0,
+        }
+    }
+
+    /// Number of keys currently tracked.
+    pub(crate) fn len(&self) -> usize {
+        self.ticks.len()
+    }
+
+    /// Record `key` as the most recently used.
+    ///
+    /// Inserts the key if it wasn't already tracked.
+    pub(crate) fn touch(&mut self, key: K) {
+        if let Some(previous) = self.ticks.insert(key, self.next_tick) {
+            self.order.remove(&previous);
+        }
+
+        self.order.insert(self.next_tick, key);
+        self.next_tick += 1;
+    }
+
+    /// Stop tracking `key` without evicting anything else.
+    pub(crate) fn remove(&mut self, key: &K) {
+        if let Some(tick) = self.ticks.remove(key) {
+            self.order.remove(&tick);
+        }
+    }
+
+    /// Remove and return the least recently used key, if any.
+    pub(crate) fn pop_lru(&mut self) -> Option<K> {
+        let (&tick, &key) = self.order.iter().next()?;
+        self.order.remove(&tick);
+        self.ticks.remove(&key);
+
+        Some(key)
+    }
+}
+
+impl<K> Default for LruIndex<K>
+where
+    K: Copy + Eq + Hash + Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LruIndex;
+
+    #[test]
+    fn test_eviction_order() {
+        let mut lru = LruIndex::new();
+        lru.touch(1);
+        lru.touch(2);
+        lru.touch(3);
+
+        // Re-touching 1 makes 2 the least recently used.
+        lru.touch(1);
+        assert_eq!(3, lru.len());
+        assert_eq!(Some(2), lru.pop_lru());
+        assert_eq!(Some(3), lru.pop_lru());
+        assert_eq!(Some(1), lru.pop_lru());
+        assert_eq!(None, lru.pop_lru());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut lru = LruIndex::new();
+        lru.touch(1);
+        lru.touch(2);
+        lru.remove(&1);
+
+        assert_eq!(1, lru.len());
+        assert_eq!(Some(2), lru.pop_lru());
+        assert!(lru.pop_lru().is_none());
+    }
+}